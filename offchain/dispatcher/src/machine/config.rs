@@ -0,0 +1,41 @@
+// Copyright 2022 Cartesi Pte. Ltd.
+//
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+// this file except in compliance with the License. You may obtain a copy of the
+// License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed
+// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
+// CONDITIONS OF ANY KIND, either express or implied. See the License for the
+// specific language governing permissions and limitations under the License.
+
+use std::time::Duration;
+
+use rollups_events::{DAppMetadata, RedactedUrl};
+
+/// Configuration for the broker facade.
+#[derive(Debug, Clone)]
+pub struct BrokerConfig {
+    pub redis_endpoint: RedactedUrl,
+    pub chain_id: u64,
+    pub dapp_contract_address: [u8; 20],
+
+    /// Extra dapps whose claims streams the consumer side should fan in, on
+    /// top of the node's own dapp. A single-dapp deployment leaves this empty.
+    pub additional_dapps: Vec<DAppMetadata>,
+
+    /// Number of blocks per epoch; an input at block `B` belongs to epoch
+    /// `floor((B - genesis_block) / epoch_length)`.
+    pub epoch_length: u64,
+
+    /// Block at which epoch 0 begins.
+    pub genesis_block: u64,
+
+    /// Downgrade out-of-order claim indexes to a logged warning instead of a
+    /// hard error, for operators who tolerate gaps during resync.
+    pub allow_claim_gaps: bool,
+
+    pub claims_consume_timeout: usize,
+    pub backoff_max_elapsed_duration: Duration,
+}
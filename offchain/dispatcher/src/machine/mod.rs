@@ -0,0 +1,71 @@
+// Copyright 2022 Cartesi Pte. Ltd.
+//
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+// this file except in compliance with the License. You may obtain a copy of the
+// License at http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed
+// under the License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR
+// CONDITIONS OF ANY KIND, either express or implied. See the License for the
+// specific language governing permissions and limitations under the License.
+
+pub mod config;
+mod rollups_broker;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use rollups_events::{DAppMetadata, HASH_SIZE};
+use types::foldables::input_box::Input;
+
+pub use rollups_broker::{
+    start, BrokerClient, BrokerFacade, BrokerFacadeError, BrokerListener,
+};
+
+/// Current state of an inputs stream.
+#[derive(Debug, Default, Clone)]
+pub struct RollupStatus {
+    pub inputs_sent_count: u64,
+    pub last_event_is_finish_epoch: bool,
+}
+
+/// A claim consumed from the broker, tagged with the dapp that produced it so
+/// downstream consumers know where to forward it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RollupClaim {
+    pub dapp_metadata: DAppMetadata,
+    pub hash: [u8; HASH_SIZE],
+    pub number: u64,
+}
+
+#[async_trait]
+pub trait BrokerStatus: Send + Sync {
+    async fn status(&self) -> Result<RollupStatus>;
+}
+
+#[async_trait]
+pub trait BrokerSend: Send + Sync {
+    /// Enqueue an input, stamping it with the block-derived epoch index.
+    async fn enqueue_input(
+        &self,
+        input_index: u64,
+        input: &Input,
+    ) -> Result<()>;
+
+    /// Produce a finish-epoch event closing the current epoch.
+    async fn finish_epoch(&self, inputs_sent_count: u64) -> Result<()>;
+
+    /// Whether advancing from `previous_block` to `new_block` crosses an epoch
+    /// boundary, signalling the caller to [`finish_epoch`](Self::finish_epoch).
+    fn epoch_crossed_by_block(
+        &self,
+        previous_block: u64,
+        new_block: u64,
+    ) -> bool;
+}
+
+#[async_trait]
+pub trait BrokerReceive: Send + Sync {
+    async fn next_claim(&self) -> Result<Option<RollupClaim>>;
+}
@@ -10,16 +10,18 @@
 // CONDITIONS OF ANY KIND, either express or implied. See the License for the
 // specific language governing permissions and limitations under the License.
 
+use std::collections::HashMap;
+
 use anyhow::Result;
 use async_trait::async_trait;
-use backoff::ExponentialBackoffBuilder;
+use backoff::{backoff::Backoff, ExponentialBackoffBuilder};
 use snafu::{ResultExt, Snafu};
 use tokio::sync::{self, Mutex};
 
 use rollups_events::{
-    Broker, BrokerError, Event, InputMetadata, RollupsAdvanceStateInput,
-    RollupsClaim, RollupsClaimsStream, RollupsData, RollupsInput,
-    RollupsInputsStream, INITIAL_ID,
+    Broker, BrokerError, DAppMetadata, Event, InputMetadata,
+    RollupsAdvanceStateInput, RollupsClaim, RollupsClaimsStream, RollupsData,
+    RollupsInput, RollupsInputsStream, INITIAL_ID,
 };
 use types::foldables::input_box::Input;
 
@@ -43,14 +45,163 @@ pub enum BrokerFacadeError {
 
     #[snafu(display("error consuming claim event"))]
     ConsumeClaimError { source: BrokerError },
+
+    #[snafu(display(
+        "claim epoch index out of order (expected {expected}, got {got})"
+    ))]
+    InvalidIndexes { expected: u64, got: u64 },
+}
+
+/// Narrow view of the broker operations the facade needs.
+///
+/// Abstracting the concrete [`Broker`] behind this trait lets tests inject a
+/// mock that fails `produce`/`consume` to exercise the facade's error paths
+/// without standing up a real Redis instance.
+#[async_trait]
+pub trait BrokerClient {
+    async fn peek_latest(
+        &mut self,
+        stream: &RollupsInputsStream,
+    ) -> Result<Option<Event<RollupsInput>>, BrokerError>;
+
+    async fn produce(
+        &mut self,
+        stream: &RollupsInputsStream,
+        payload: RollupsInput,
+    ) -> Result<String, BrokerError>;
+
+    async fn consume_nonblocking(
+        &mut self,
+        stream: &RollupsClaimsStream,
+        last_id: &str,
+    ) -> Result<Option<Event<RollupsClaim>>, BrokerError>;
+
+    async fn consume_blocking(
+        &mut self,
+        stream: &RollupsClaimsStream,
+        last_id: &str,
+    ) -> Result<Option<Event<RollupsClaim>>, BrokerError>;
+}
+
+#[async_trait]
+impl BrokerClient for Broker {
+    async fn peek_latest(
+        &mut self,
+        stream: &RollupsInputsStream,
+    ) -> Result<Option<Event<RollupsInput>>, BrokerError> {
+        Broker::peek_latest(self, stream).await
+    }
+
+    async fn produce(
+        &mut self,
+        stream: &RollupsInputsStream,
+        payload: RollupsInput,
+    ) -> Result<String, BrokerError> {
+        Broker::produce(self, stream, payload).await
+    }
+
+    async fn consume_nonblocking(
+        &mut self,
+        stream: &RollupsClaimsStream,
+        last_id: &str,
+    ) -> Result<Option<Event<RollupsClaim>>, BrokerError> {
+        Broker::consume_nonblocking(self, stream, last_id).await
+    }
+
+    async fn consume_blocking(
+        &mut self,
+        stream: &RollupsClaimsStream,
+        last_id: &str,
+    ) -> Result<Option<Event<RollupsClaim>>, BrokerError> {
+        Broker::consume_blocking(self, stream, last_id).await
+    }
 }
 
 #[derive(Debug)]
-pub struct BrokerFacade {
-    broker: Mutex<Broker>,
+pub struct BrokerFacade<B = Broker> {
+    broker: Mutex<B>,
     inputs_stream: RollupsInputsStream,
-    claims_stream: RollupsClaimsStream,
-    last_claim_id: Mutex<String>,
+    claims: Mutex<ClaimsFrontier>,
+    epoch_policy: EpochPolicy,
+    allow_claim_gaps: bool,
+    // Kept so the listener can rebuild the connection when `start` reconnects
+    // after a transient failure.
+    broker_config: rollups_events::BrokerConfig,
+}
+
+/// Maps block numbers to epoch indices using a fixed block length.
+///
+/// An input observed at block `B` belongs to epoch
+/// `floor((B - genesis_block) / epoch_length)`. Deriving the epoch from the
+/// block number (rather than an implicit per-epoch counter) keeps the
+/// assignment deterministic and stable across chain reorgs.
+#[derive(Debug, Clone, Copy)]
+struct EpochPolicy {
+    genesis_block: u64,
+    epoch_length: u64,
+}
+
+impl EpochPolicy {
+    fn epoch_index(&self, block_number: u64) -> u64 {
+        // `epoch_length` is operator-supplied; clamp to at least one block so a
+        // misconfigured `0` maps every block to epoch 0 rather than panicking
+        // with a divide-by-zero.
+        block_number.saturating_sub(self.genesis_block)
+            / self.epoch_length.max(1)
+    }
+}
+
+/// A claims stream together with the dapp that produced it.
+#[derive(Debug)]
+struct DAppClaimsStream {
+    dapp_metadata: DAppMetadata,
+    stream: RollupsClaimsStream,
+}
+
+/// Fan-in state for consuming claims from every dapp the facade was built
+/// with. The set of streams is fixed at construction; streams that appear on
+/// the broker later are not picked up.
+///
+/// Each stream keeps its own `last_claim_id`, keyed by the stream key, and a
+/// round-robin cursor makes `next_claim` cycle through the dapps so that no
+/// single application can starve the others.
+#[derive(Debug)]
+struct ClaimsFrontier {
+    streams: Vec<DAppClaimsStream>,
+    last_claim_ids: HashMap<String, String>,
+    last_epochs: HashMap<String, u64>,
+    cursor: usize,
+}
+
+impl ClaimsFrontier {
+    fn new(dapps: Vec<DAppMetadata>) -> Self {
+        let streams = dapps
+            .into_iter()
+            .map(|dapp_metadata| {
+                let stream = RollupsClaimsStream::new(&dapp_metadata);
+                DAppClaimsStream {
+                    dapp_metadata,
+                    stream,
+                }
+            })
+            .collect();
+        Self {
+            streams,
+            last_claim_ids: HashMap::new(),
+            last_epochs: HashMap::new(),
+            cursor: 0,
+        }
+    }
+
+    /// The epoch index a freshly consumed claim on `key` must carry: the
+    /// successor of the last accepted one. Returns `None` before any claim has
+    /// been accepted on the stream, since `last_epochs` is in-memory and reset
+    /// on every process start — after a restart/resync the first claim handed
+    /// back may be any epoch `k`, so it is taken as the baseline rather than
+    /// forced to match zero.
+    fn expected_epoch(&self, key: &str) -> Option<u64> {
+        self.last_epochs.get(key).map(|last| last + 1)
+    }
 }
 
 struct BrokerStreamStatus {
@@ -68,39 +219,76 @@ impl BrokerFacade {
             .with_max_elapsed_time(Some(config.backoff_max_elapsed_duration))
             .build();
         let broker_config = rollups_events::BrokerConfig {
-            redis_endpoint: config.redis_endpoint,
+            redis_endpoint: config.redis_endpoint.clone(),
             backoff,
             consume_timeout: config.claims_consume_timeout,
         };
-        let broker = Mutex::new(
-            Broker::new(broker_config)
-                .await
-                .context(BrokerConnectionSnafu)?,
-        );
+        let broker = Broker::new(broker_config)
+            .await
+            .context(BrokerConnectionSnafu)?;
 
         tracing::trace!("connected to the broker successfully");
 
-        let dapp_metadata = rollups_events::DAppMetadata {
+        let dapp_metadata = DAppMetadata {
             chain_id: config.chain_id,
             dapp_address: config.dapp_contract_address.into(),
         };
 
+        // The send side is always pinned to the node's own dapp; the consume
+        // side fans in every dapp the operator asked to serve, defaulting to
+        // the primary one so a single-dapp deployment behaves as before.
+        let mut dapps = config.additional_dapps.clone();
+        if !dapps.contains(&dapp_metadata) {
+            dapps.insert(0, dapp_metadata);
+        }
+
+        Ok(Self::with_client(broker, dapps, config))
+    }
+}
+
+impl<B: BrokerClient + Send> BrokerFacade<B> {
+    /// Builds a facade around a pre-built broker client. Separating this from
+    /// [`new`](BrokerFacade::new) lets tests inject a mock client to drive the
+    /// produce/consume error paths.
+    pub fn with_client(
+        client: B,
+        dapps: Vec<DAppMetadata>,
+        config: BrokerConfig,
+    ) -> Self {
+        let dapp_metadata = DAppMetadata {
+            chain_id: config.chain_id,
+            dapp_address: config.dapp_contract_address.into(),
+        };
         let inputs_stream = RollupsInputsStream::new(&dapp_metadata);
 
-        let claims_stream = RollupsClaimsStream::new(&dapp_metadata);
+        let epoch_policy = EpochPolicy {
+            genesis_block: config.genesis_block,
+            epoch_length: config.epoch_length,
+        };
 
-        Ok(Self {
-            broker,
+        let backoff = ExponentialBackoffBuilder::new()
+            .with_max_elapsed_time(Some(config.backoff_max_elapsed_duration))
+            .build();
+        let broker_config = rollups_events::BrokerConfig {
+            redis_endpoint: config.redis_endpoint.clone(),
+            backoff,
+            consume_timeout: config.claims_consume_timeout,
+        };
+
+        Self {
+            broker: Mutex::new(client),
             inputs_stream,
-            claims_stream,
-            last_claim_id: Mutex::new(INITIAL_ID.to_owned()),
-        })
+            claims: Mutex::new(ClaimsFrontier::new(dapps)),
+            epoch_policy,
+            allow_claim_gaps: config.allow_claim_gaps,
+            broker_config,
+        }
     }
 
     #[tracing::instrument(level = "trace", skip_all)]
     async fn broker_status(
         &self,
-        broker: &mut sync::MutexGuard<'_, Broker>,
+        broker: &mut sync::MutexGuard<'_, B>,
     ) -> Result<BrokerStreamStatus> {
         let event = self.peek(broker).await?;
         Ok(event.into())
@@ -109,7 +297,7 @@ impl BrokerFacade {
     #[tracing::instrument(level = "trace", skip_all)]
     async fn peek(
         &self,
-        broker: &mut sync::MutexGuard<'_, Broker>,
+        broker: &mut sync::MutexGuard<'_, B>,
     ) -> Result<Option<Event<RollupsInput>>> {
         tracing::trace!("peeking last produced event");
         let response = broker
@@ -122,21 +310,135 @@ impl BrokerFacade {
     }
 
     #[tracing::instrument(level = "trace", skip_all)]
-    async fn claim(&self, id: &String) -> Result<Option<Event<RollupsClaim>>> {
+    async fn claim(
+        &self,
+        stream: &RollupsClaimsStream,
+        id: &String,
+        blocking: bool,
+    ) -> Result<Option<Event<RollupsClaim>>, BrokerFacadeError> {
         let mut broker = self.broker.lock().await;
-        let event = broker
-            .consume_nonblocking(&self.claims_stream, id)
-            .await
-            .context(ConsumeClaimSnafu)?;
+        let event = if blocking {
+            broker
+                .consume_blocking(stream, id)
+                .await
+                .context(ConsumeClaimSnafu)?
+        } else {
+            broker
+                .consume_nonblocking(stream, id)
+                .await
+                .context(ConsumeClaimSnafu)?
+        };
 
         tracing::trace!(?event, "consumed event");
 
         Ok(event)
     }
+
+    /// Probes the configured claims streams round-robin and returns the first
+    /// valid claim found, advancing the per-stream frontier.
+    ///
+    /// Every stream is first probed non-blocking, which is cheap and serves a
+    /// pending claim on any dapp immediately. Only when all streams are empty
+    /// and `blocking` is set does the facade block — on a single stream (the
+    /// one under the cursor) for one `claims_consume_timeout`, rotating the
+    /// cursor so each dapp takes a turn. This bounds an idle N-dapp node to one
+    /// timeout per cycle instead of the `N × timeout` a serial blocking sweep
+    /// over a single connection would cost.
+    #[tracing::instrument(level = "trace", skip_all)]
+    async fn consume_claim(
+        &self,
+        blocking: bool,
+    ) -> Result<Option<super::RollupClaim>, BrokerFacadeError> {
+        let mut claims = self.claims.lock().await;
+        let stream_count = claims.streams.len();
+        if stream_count == 0 {
+            return Ok(None);
+        }
+
+        // Non-blocking round-robin: start at the cursor and probe each dapp
+        // once, returning the first claim found so that a busy dapp cannot
+        // monopolise the consumer.
+        let start = claims.cursor;
+        for offset in 0..stream_count {
+            let index = (start + offset) % stream_count;
+            if let Some(claim) = self.try_consume(&mut claims, index, false).await? {
+                claims.cursor = (index + 1) % stream_count;
+                return Ok(Some(claim));
+            }
+        }
+
+        if !blocking {
+            return Ok(None);
+        }
+
+        // Every stream was empty: block on just the cursor stream for one
+        // timeout and rotate, so the blocking wait is bounded per cycle.
+        let index = start % stream_count;
+        claims.cursor = (index + 1) % stream_count;
+        self.try_consume(&mut claims, index, true).await
+    }
+
+    /// Consumes one claim from the stream at `index`, validating its epoch
+    /// index against the per-stream frontier and advancing the bookkeeping on
+    /// success. Returns `None` when the stream yields no claim.
+    #[tracing::instrument(level = "trace", skip_all)]
+    async fn try_consume(
+        &self,
+        claims: &mut ClaimsFrontier,
+        index: usize,
+        blocking: bool,
+    ) -> Result<Option<super::RollupClaim>, BrokerFacadeError> {
+        let (stream, dapp_metadata, last_id) = {
+            let entry = &claims.streams[index];
+            let key = entry.stream.key().to_owned();
+            let last_id = claims
+                .last_claim_ids
+                .get(&key)
+                .cloned()
+                .unwrap_or_else(|| INITIAL_ID.to_owned());
+            (entry.stream.clone(), entry.dapp_metadata.clone(), last_id)
+        };
+
+        tracing::trace!(?dapp_metadata, ?last_id, "getting next epoch claim");
+
+        let event = match self.claim(&stream, &last_id, blocking).await? {
+            Some(event) => event,
+            None => return Ok(None),
+        };
+
+        let key = stream.key().to_owned();
+        let got = event.payload.epoch_index;
+        // Only validate ordering once a baseline exists; the first claim
+        // observed on a stream is accepted as-is (see `expected_epoch`).
+        if let Some(expected) = claims.expected_epoch(&key) {
+            if got != expected {
+                // A gap or reordering means the operator would skip or
+                // double-count an epoch; refuse to advance unless the
+                // operator explicitly tolerates gaps during resync.
+                if self.allow_claim_gaps {
+                    tracing::warn!(
+                        ?dapp_metadata,
+                        expected,
+                        got,
+                        "claim epoch index out of order; accepting anyway"
+                    );
+                } else {
+                    return Err(BrokerFacadeError::InvalidIndexes {
+                        expected,
+                        got,
+                    });
+                }
+            }
+        }
+
+        claims.last_epochs.insert(key.clone(), got);
+        claims.last_claim_ids.insert(key, event.id.clone());
+        Ok(Some(rollup_claim(event, dapp_metadata)))
+    }
 }
 
 #[async_trait]
-impl BrokerStatus for BrokerFacade {
+impl<B: BrokerClient + Send> BrokerStatus for BrokerFacade<B> {
     #[tracing::instrument(level = "trace", skip_all)]
     async fn status(&self) -> Result<RollupStatus> {
         tracing::trace!("querying broker status");
@@ -148,7 +450,7 @@ impl BrokerStatus for BrokerFacade {
 }
 
 macro_rules! input_sanity_check {
-    ($event:expr, $input_index:expr) => {
+    ($event:expr, $input_index:expr, $epoch_index:expr) => {
         assert_eq!($event.inputs_sent_count, $input_index + 1);
         assert!(matches!(
             $event.data,
@@ -158,7 +460,7 @@ macro_rules! input_sanity_check {
                     ..
                 },
                 ..
-            }) if epoch_index == 0
+            }) if epoch_index == $epoch_index
         ));
         assert!(matches!(
             $event.data,
@@ -181,7 +483,7 @@ macro_rules! epoch_sanity_check {
 }
 
 #[async_trait]
-impl BrokerSend for BrokerFacade {
+impl<B: BrokerClient + Send> BrokerSend for BrokerFacade<B> {
     #[tracing::instrument(level = "trace", skip_all)]
     async fn enqueue_input(
         &self,
@@ -193,10 +495,13 @@ impl BrokerSend for BrokerFacade {
         let mut broker = self.broker.lock().await;
         let status = self.broker_status(&mut broker).await?;
 
-        let event = build_next_input(input, &status);
+        let epoch_index = self
+            .epoch_policy
+            .epoch_index(input.block_added.number.as_u64());
+        let event = build_next_input(input, &status, epoch_index);
         tracing::trace!(?event, "producing input event");
 
-        input_sanity_check!(event, input_index);
+        input_sanity_check!(event, input_index, epoch_index);
 
         let id = broker
             .produce(&self.inputs_stream, event)
@@ -228,21 +533,124 @@ impl BrokerSend for BrokerFacade {
 
         Ok(())
     }
+
+    #[tracing::instrument(level = "trace", skip_all)]
+    fn epoch_crossed_by_block(
+        &self,
+        previous_block: u64,
+        new_block: u64,
+    ) -> bool {
+        self.epoch_policy.epoch_index(new_block)
+            > self.epoch_policy.epoch_index(previous_block)
+    }
 }
 
 #[async_trait]
-impl BrokerReceive for BrokerFacade {
+impl<B: BrokerClient + Send> BrokerReceive for BrokerFacade<B> {
     #[tracing::instrument(level = "trace", skip_all)]
     async fn next_claim(&self) -> Result<Option<super::RollupClaim>> {
-        let mut last_id = self.last_claim_id.lock().await;
-        tracing::trace!(?last_id, "getting next epoch claim");
+        Ok(self.consume_claim(false).await?)
+    }
+}
+
+impl BrokerFacadeError {
+    /// Whether the error is a transient broker failure that a reconnect may
+    /// recover from, as opposed to a logic error that should stop the loop.
+    fn is_transient(&self) -> bool {
+        matches!(self, BrokerFacadeError::ConsumeClaimError { .. })
+    }
+}
+
+/// Consumer side of the broker modelled as a long-running listener rather than
+/// a one-shot poll. [`start`] drives the loop, blocking on the claims stream
+/// and reconnecting on transient errors, so the facade can be wired straight
+/// into a downstream duplicate-checker + sender pipeline.
+#[async_trait]
+pub trait BrokerListener {
+    type Error: std::error::Error;
+
+    /// Block until the next claim is available on the broker.
+    async fn listen(&mut self) -> Result<super::RollupClaim, Self::Error>;
 
-        match self.claim(&last_id).await? {
-            Some(event) => {
-                *last_id = event.id.clone();
-                Ok(Some(event.into()))
+    /// Re-establish the broker connection after a transient failure.
+    async fn reconnect(&mut self) -> Result<(), Self::Error>;
+
+    /// Classifies an error raised by [`listen`](Self::listen) as transient
+    /// (worth reconnecting) or terminal.
+    fn is_transient(error: &Self::Error) -> bool;
+}
+
+#[async_trait]
+impl BrokerListener for BrokerFacade<Broker> {
+    type Error = BrokerFacadeError;
+
+    #[tracing::instrument(level = "trace", skip_all)]
+    async fn listen(&mut self) -> Result<super::RollupClaim, Self::Error> {
+        loop {
+            // `consume_claim(true)` blocks on the cursor stream for the
+            // configured timeout; on timeout it returns `None` and we retry so
+            // that a quiet broker does not terminate the listener.
+            if let Some(claim) = self.consume_claim(true).await? {
+                return Ok(claim);
             }
-            None => Ok(None),
+        }
+    }
+
+    #[tracing::instrument(level = "trace", skip_all)]
+    async fn reconnect(&mut self) -> Result<(), Self::Error> {
+        let broker = Broker::new(self.broker_config.clone())
+            .await
+            .context(BrokerConnectionSnafu)?;
+        *self.broker.lock().await = broker;
+        Ok(())
+    }
+
+    fn is_transient(error: &Self::Error) -> bool {
+        error.is_transient()
+    }
+}
+
+/// Drives a [`BrokerListener`] forever, forwarding every observed claim to
+/// `sink`. Transient errors trigger an exponential-backoff sleep followed by a
+/// reconnect before the loop retries, so a persistent transient failure does
+/// not spin in a tight busy-loop; terminal errors (e.g.
+/// [`BrokerFacadeError::InvalidIndexes`]) propagate and stop the listener.
+#[tracing::instrument(level = "trace", skip_all)]
+pub async fn start<L, S, F>(mut listener: L, mut sink: S) -> Result<()>
+where
+    L: BrokerListener + Send,
+    L::Error: Send + Sync + 'static,
+    S: FnMut(super::RollupClaim) -> F + Send,
+    F: std::future::Future<Output = Result<()>> + Send,
+{
+    let mut backoff = ExponentialBackoffBuilder::new().build();
+    loop {
+        match listener.listen().await {
+            Ok(claim) => {
+                backoff.reset();
+                sink(claim).await?;
+            }
+            Err(error) if L::is_transient(&error) => {
+                // Grow the wait on each consecutive failure so a broker that
+                // stays down is retried with ever-larger gaps rather than
+                // hammered. `next_backoff` only returns `None` past the
+                // builder's default max elapsed time (this backoff does not
+                // use `backoff_max_elapsed_duration`); fall back to the largest
+                // interval so the loop keeps trying indefinitely.
+                let wait = backoff
+                    .next_backoff()
+                    .unwrap_or(backoff.max_interval);
+                tracing::warn!(
+                    %error,
+                    ?wait,
+                    "transient broker error; backing off before reconnecting"
+                );
+                tokio::time::sleep(wait).await;
+                if let Err(error) = listener.reconnect().await {
+                    tracing::warn!(%error, "reconnect failed; will retry");
+                }
+            }
+            Err(error) => return Err(error.into()),
         }
     }
 }
@@ -269,20 +677,17 @@ impl From<Event<RollupsInput>> for BrokerStreamStatus {
     fn from(event: Event<RollupsInput>) -> Self {
         let id = event.id;
         let payload = event.payload;
-        let epoch_index = payload.epoch_index;
-
-        match payload.data {
-            RollupsData::AdvanceStateInput { .. } => Self {
-                id,
-                epoch_number: epoch_index,
-                status: payload.into(),
-            },
-
-            RollupsData::FinishEpoch { .. } => Self {
-                id,
-                epoch_number: epoch_index + 1,
-                status: payload.into(),
-            },
+        // Epoch numbering is driven entirely by the block-derived policy that
+        // `build_next_input` stamps, so the last event's `epoch_index` is the
+        // current epoch for both advance inputs and finish-epoch events. Do not
+        // `+1` on finish: the epoch only advances when the block number crosses
+        // a boundary, otherwise a finish and the inputs it closes would
+        // disagree on the epoch number.
+        let epoch_number = payload.epoch_index;
+        Self {
+            id,
+            epoch_number,
+            status: payload.into(),
         }
     }
 }
@@ -304,12 +709,13 @@ impl From<Option<Event<RollupsInput>>> for BrokerStreamStatus {
 fn build_next_input(
     input: &Input,
     status: &BrokerStreamStatus,
+    epoch_index: u64,
 ) -> RollupsInput {
     let metadata = InputMetadata {
         msg_sender: input.sender.to_fixed_bytes().into(),
         block_number: input.block_added.number.as_u64(),
         timestamp: input.block_added.timestamp.as_u64(),
-        epoch_index: 0,
+        epoch_index,
         input_index: status.status.inputs_sent_count,
     };
 
@@ -321,7 +727,7 @@ fn build_next_input(
 
     RollupsInput {
         parent_id: status.id.clone(),
-        epoch_index: status.epoch_number,
+        epoch_index,
         inputs_sent_count: status.status.inputs_sent_count + 1,
         data,
     }
@@ -336,12 +742,17 @@ fn build_next_finish_epoch(status: &BrokerStreamStatus) -> RollupsInput {
     }
 }
 
-impl From<Event<RollupsClaim>> for super::RollupClaim {
-    fn from(event: Event<RollupsClaim>) -> Self {
-        super::RollupClaim {
-            hash: event.payload.claim.into_inner(),
-            number: event.payload.epoch_index,
-        }
+/// Builds a [`super::RollupClaim`] from a consumed claim event, tagging it with
+/// the dapp whose stream produced it so downstream consumers know where to
+/// forward the claim.
+fn rollup_claim(
+    event: Event<RollupsClaim>,
+    dapp_metadata: DAppMetadata,
+) -> super::RollupClaim {
+    super::RollupClaim {
+        dapp_metadata,
+        hash: event.payload.claim.into_inner(),
+        number: event.payload.epoch_index,
     }
 }
 
@@ -349,9 +760,12 @@ impl From<Event<RollupsClaim>> for super::RollupClaim {
 mod broker_facade_tests {
     use std::{sync::Arc, time::Duration};
 
+    use async_trait::async_trait;
     use rollups_events::{
-        Hash, InputMetadata, Payload, RedactedUrl, RollupsAdvanceStateInput,
-        RollupsData, Url, HASH_SIZE,
+        BrokerError, DAppMetadata, Event, Hash, InputMetadata, Payload,
+        RedactedUrl, RollupsAdvanceStateInput, RollupsClaim,
+        RollupsClaimsStream, RollupsData, RollupsInput, RollupsInputsStream,
+        Url, HASH_SIZE, INITIAL_ID,
     };
     use state_fold_types::{
         ethereum_types::{Bloom, H160, H256, U256, U64},
@@ -365,7 +779,7 @@ mod broker_facade_tests {
         config::BrokerConfig, BrokerReceive, BrokerSend, BrokerStatus,
     };
 
-    use super::BrokerFacade;
+    use super::{BrokerClient, BrokerFacade};
 
     // --------------------------------------------------------------------------------------------
     // new
@@ -385,6 +799,10 @@ mod broker_facade_tests {
                 .expect("failed to parse Redis Url"),
             chain_id: 1,
             dapp_contract_address: [0; 20],
+            additional_dapps: vec![],
+            epoch_length: 7200,
+            genesis_block: 0,
+            allow_claim_gaps: false,
             claims_consume_timeout: 300000,
             backoff_max_elapsed_duration: Duration::from_millis(1000),
         })
@@ -489,7 +907,21 @@ mod broker_facade_tests {
         let _ = broker.enqueue_input(5, &new_enqueue_input()).await;
     }
 
-    // NOTE: cannot test result error because the dependency is not injectable.
+    #[tokio::test]
+    async fn enqueue_input_produce_error() {
+        let broker = mock_facade(MockBroker {
+            fail_produce: true,
+            ..Default::default()
+        });
+        let error = broker
+            .enqueue_input(0, &new_enqueue_input())
+            .await
+            .err()
+            .expect("'enqueue_input' function has not failed")
+            .to_string();
+        // BrokerFacadeError::ProduceInputError
+        assert_eq!(error, "error producing input event");
+    }
 
     // --------------------------------------------------------------------------------------------
     // finish_epoch
@@ -523,7 +955,21 @@ mod broker_facade_tests {
         let _ = broker.finish_epoch(1).await;
     }
 
-    // NOTE: cannot test result error because the dependency is not injectable.
+    #[tokio::test]
+    async fn finish_epoch_produce_error() {
+        let broker = mock_facade(MockBroker {
+            fail_produce: true,
+            ..Default::default()
+        });
+        let error = broker
+            .finish_epoch(0)
+            .await
+            .err()
+            .expect("'finish_epoch' function has not failed")
+            .to_string();
+        // BrokerFacadeError::ProduceFinishError
+        assert_eq!(error, "error producing finish-epoch event");
+    }
 
     // --------------------------------------------------------------------------------------------
     // next_claim
@@ -592,6 +1038,22 @@ mod broker_facade_tests {
         }
     }
 
+    #[tokio::test]
+    async fn next_claim_consume_error() {
+        let broker = mock_facade(MockBroker {
+            fail_consume: true,
+            ..Default::default()
+        });
+        let error = broker
+            .next_claim()
+            .await
+            .err()
+            .expect("'next_claim' function has not failed")
+            .to_string();
+        // BrokerFacadeError::ConsumeClaimError
+        assert_eq!(error, "error consuming claim event");
+    }
+
     // --------------------------------------------------------------------------------------------
     // auxiliary
     // --------------------------------------------------------------------------------------------
@@ -602,6 +1064,10 @@ mod broker_facade_tests {
             redis_endpoint: fixture.redis_endpoint().to_owned(),
             chain_id: fixture.chain_id(),
             dapp_contract_address: fixture.dapp_address().inner().to_owned(),
+            additional_dapps: vec![],
+            epoch_length: 7200,
+            genesis_block: 0,
+            allow_claim_gaps: false,
             claims_consume_timeout: 300000,
             backoff_max_elapsed_duration: Duration::from_millis(1000),
         };
@@ -654,4 +1120,76 @@ mod broker_facade_tests {
         }
         hashes
     }
+
+    // A broker client whose `produce`/`consume` operations can be made to
+    // fail on demand, so the facade's error paths can be exercised without a
+    // real Redis instance.
+    #[derive(Debug, Default)]
+    struct MockBroker {
+        fail_produce: bool,
+        fail_consume: bool,
+    }
+
+    #[async_trait]
+    impl BrokerClient for MockBroker {
+        async fn peek_latest(
+            &mut self,
+            _stream: &RollupsInputsStream,
+        ) -> Result<Option<Event<RollupsInput>>, BrokerError> {
+            Ok(None)
+        }
+
+        async fn produce(
+            &mut self,
+            _stream: &RollupsInputsStream,
+            _payload: RollupsInput,
+        ) -> Result<String, BrokerError> {
+            if self.fail_produce {
+                Err(BrokerError::ConsumeTimeout)
+            } else {
+                Ok(INITIAL_ID.to_owned())
+            }
+        }
+
+        async fn consume_nonblocking(
+            &mut self,
+            _stream: &RollupsClaimsStream,
+            _last_id: &str,
+        ) -> Result<Option<Event<RollupsClaim>>, BrokerError> {
+            if self.fail_consume {
+                Err(BrokerError::ConsumeTimeout)
+            } else {
+                Ok(None)
+            }
+        }
+
+        async fn consume_blocking(
+            &mut self,
+            stream: &RollupsClaimsStream,
+            last_id: &str,
+        ) -> Result<Option<Event<RollupsClaim>>, BrokerError> {
+            self.consume_nonblocking(stream, last_id).await
+        }
+    }
+
+    fn mock_facade(mock: MockBroker) -> BrokerFacade<MockBroker> {
+        let config = BrokerConfig {
+            redis_endpoint: Url::parse("redis://invalid")
+                .map(RedactedUrl::new)
+                .expect("failed to parse Redis Url"),
+            chain_id: 1,
+            dapp_contract_address: [0; 20],
+            additional_dapps: vec![],
+            epoch_length: 7200,
+            genesis_block: 0,
+            allow_claim_gaps: false,
+            claims_consume_timeout: 300000,
+            backoff_max_elapsed_duration: Duration::from_millis(1000),
+        };
+        let dapp_metadata = DAppMetadata {
+            chain_id: config.chain_id,
+            dapp_address: config.dapp_contract_address.into(),
+        };
+        BrokerFacade::with_client(mock, vec![dapp_metadata], config)
+    }
 }